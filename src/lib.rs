@@ -1,6 +1,41 @@
 use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 
-type F = f32;
+use num_traits::{Float, Num, NumAssign};
+
+/// A scalar type usable in operations that need floating-point behavior, such as division,
+/// absolute value, and comparisons near zero (e.g. the LU decomposition and anything built on
+/// top of it).
+pub trait Real: Float + NumAssign {}
+impl<T: Float + NumAssign> Real for T {}
+
+/// A [`Matrix`] of `f32`, the scalar type matrijs used before [`Matrix`] became generic. Kept
+/// around so existing callers don't have to spell out `Matrix<f32>`.
+pub type Matrixf32 = Matrix<f32>;
+
+/// Quickly build a [`Matrix`] from a semicolon-separated list of rows.
+///
+/// ```
+/// use matrijs::matrix;
+///
+/// let m = matrix![0.0, 1.0; -1.0, 0.0];
+/// assert_eq!(m.shape(), (2, 2));
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ( $( $( $x:expr ),+ );+ $(;)? ) => {{
+        let rows: &[&[_]] = &[ $( &[ $($x),+ ] ),+ ];
+        let n_rows = rows.len();
+        let n_cols = rows[0].len();
+
+        let mut array = Vec::with_capacity(n_rows * n_cols);
+        for row in rows {
+            assert_eq!(row.len(), n_cols, "all rows passed to matrix! must have the same length");
+            array.extend_from_slice(row);
+        }
+
+        $crate::Matrix::new(n_rows, n_cols, &array)
+    }};
+}
 
 #[derive(Debug, Clone, PartialEq)]
 /// A 2-dimensional matrix.
@@ -19,13 +54,13 @@ type F = f32;
 /// # Note
 ///
 /// The implementation is row-major, at the moment.
-pub struct Matrix {
+pub struct Matrix<T> {
     cols: usize,
     rows: usize,
-    array: Vec<F>, // length == cols * rows
+    array: Vec<T>, // length == cols * rows
 }
 
-impl Matrix {
+impl<T: Num + NumAssign + Copy> Matrix<T> {
     /// Returns the cols of this [`Matrix`].
     pub fn cols(&self) -> usize {
         self.cols
@@ -37,7 +72,7 @@ impl Matrix {
     }
 
     /// Returns a reference to the internal array of this [`Matrix`].
-    pub fn array(&self) -> &[F] {
+    pub fn array(&self) -> &[T] {
         &self.array
     }
 
@@ -47,13 +82,13 @@ impl Matrix {
     }
 }
 
-impl Matrix {
+impl<T: Num + NumAssign + Copy> Matrix<T> {
     /// Get a slice to the `index`th row.
     ///
     /// # Panics
     ///
     /// If `index` >= `rows`, this function will panic.
-    pub fn row(&self, index: usize) -> &[F] {
+    pub fn row(&self, index: usize) -> &[T] {
         &self.array[index * self.cols..(index + 1) * self.cols]
     }
 
@@ -62,7 +97,7 @@ impl Matrix {
     /// # Panics
     ///
     /// If `index` >= `rows`, this function will panic.
-    pub fn row_mut(&mut self, index: usize) -> &mut [F] {
+    pub fn row_mut(&mut self, index: usize) -> &mut [T] {
         &mut self.array[index * self.cols..(index + 1) * self.cols]
     }
 
@@ -71,7 +106,7 @@ impl Matrix {
     /// # Panics
     ///
     /// If `index` >= `cols`, this function will panic.
-    pub fn col(&self, index: usize) -> Vec<F> {
+    pub fn col(&self, index: usize) -> Vec<T> {
         let mut col = Vec::with_capacity(self.rows);
         for i in 0..self.rows {
             col.push(self[(i, index)])
@@ -81,8 +116,47 @@ impl Matrix {
     }
 }
 
-impl Matrix {
-    pub fn new(rows: usize, cols: usize, array: &[F]) -> Self {
+impl<T: Num + NumAssign + Copy> Matrix<T> {
+    /// Iterate over every element, in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.array.iter()
+    }
+
+    /// Mutably iterate over every element, in row-major order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.array.iter_mut()
+    }
+
+    /// Iterate over the rows of this matrix, each as a slice.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.array.chunks(self.cols)
+    }
+
+    /// Iterate over every `(row, col)` index pair, in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use matrijs::Matrix;
+    ///
+    /// let m: Matrix<f32> = Matrix::zero(2, 2);
+    /// let idx: Vec<_> = m.indices().collect();
+    /// assert_eq!(idx, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    /// ```
+    pub fn indices(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+
+        (0..self.rows).flat_map(move |i| (0..cols).map(move |j| (i, j)))
+    }
+
+    /// Iterate over `((row, col), &element)` pairs, in row-major order.
+    pub fn iter_indexed(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.indices().zip(self.iter())
+    }
+}
+
+impl<T: Num + NumAssign + Copy> Matrix<T> {
+    pub fn new(rows: usize, cols: usize, array: &[T]) -> Self {
         assert_eq!(
             array.len(),
             cols * rows,
@@ -96,7 +170,7 @@ impl Matrix {
         }
     }
 
-    pub fn with_value(rows: usize, cols: usize, value: F) -> Self {
+    pub fn with_value(rows: usize, cols: usize, value: T) -> Self {
         Self {
             cols,
             rows,
@@ -105,23 +179,23 @@ impl Matrix {
     }
 
     pub fn zero(rows: usize, cols: usize) -> Self {
-        Self::with_value(rows, cols, 0.0)
+        Self::with_value(rows, cols, T::zero())
     }
 
     pub fn one(rows: usize, cols: usize) -> Self {
-        Self::with_value(rows, cols, 1.0)
+        Self::with_value(rows, cols, T::one())
     }
 
     pub fn identity(size: usize) -> Self {
         let mut i = Self::zero(size, size);
         for index in 0..size {
-            i[(index, index)] = 1.0
+            i[(index, index)] = T::one()
         }
 
         i
     }
 
-    pub fn diagonal(array: &[F]) -> Self {
+    pub fn diagonal(array: &[T]) -> Self {
         let size = array.len();
 
         let mut d = Self::zero(size, size);
@@ -133,7 +207,51 @@ impl Matrix {
     }
 }
 
-impl Matrix {
+impl<T: Num + NumAssign + Copy> Matrix<T> {
+    /// Apply `f` to every element, returning a new [`Matrix`] of the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use matrijs::Matrix;
+    ///
+    /// let m = Matrix::new(1, 3, &[-1.0, 0.0, 1.0]);
+    /// let relu = m.map(|x: f32| x.max(0.0));
+    /// assert_eq!(relu, Matrix::new(1, 3, &[0.0, 0.0, 1.0]));
+    /// ```
+    pub fn map<Func: Fn(T) -> T>(&self, f: Func) -> Self {
+        Self {
+            cols: self.cols,
+            rows: self.rows,
+            array: self.array.iter().map(|&x| f(x)).collect(),
+        }
+    }
+
+    /// Apply `f` to every element in place.
+    pub fn map_mut<Func: Fn(T) -> T>(&mut self, f: Func) {
+        self.array.iter_mut().for_each(|x| *x = f(*x));
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Matrix<f32> {
+    /// Build a [`Matrix`] filled with values drawn uniformly from `[0.0, 1.0)`.
+    pub fn rand(rows: usize, cols: usize) -> Self {
+        Self::rand_range(rows, cols, 0.0, 1.0)
+    }
+
+    /// Build a [`Matrix`] filled with values drawn uniformly from `[lo, hi)`.
+    pub fn rand_range(rows: usize, cols: usize, lo: f32, hi: f32) -> Self {
+        let mut rng = rand::thread_rng();
+        let array = (0..rows * cols)
+            .map(|_| rand::Rng::gen_range(&mut rng, lo..hi))
+            .collect();
+
+        Self { cols, rows, array }
+    }
+}
+
+impl<T: Num + NumAssign + Copy> Matrix<T> {
     /// Transpose a [`Matrix`] in place.
     pub fn transpose(&mut self) {
         let mut new_array = Vec::with_capacity(self.rows * self.cols);
@@ -154,11 +272,11 @@ impl Matrix {
     }
 }
 
-impl Matrix {
+impl<T: Num + NumAssign + Copy> Matrix<T> {
     // TODO: Maybe I could simply do a transpose followed by append_row then transpose again
     // instead. But that might be slower because I would need to do a lot of shuffling until I
     // implement a more efficient way of transposing in place.
-    pub fn append_col(&mut self, col: &[F]) {
+    pub fn append_col(&mut self, col: &[T]) {
         assert_eq!(
             col.len(),
             self.rows,
@@ -180,7 +298,7 @@ impl Matrix {
         debug_assert_eq!(self.array.len(), self.cols * self.rows);
     }
 
-    pub fn append_row(&mut self, row: &[F]) {
+    pub fn append_row(&mut self, row: &[T]) {
         assert_eq!(
             row.len(),
             self.cols,
@@ -194,8 +312,8 @@ impl Matrix {
     }
 }
 
-impl Index<(usize, usize)> for Matrix {
-    type Output = F;
+impl<T: Num + NumAssign + Copy> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
 
     /// Get element by `(row, col)`.
     fn index(&self, index: (usize, usize)) -> &Self::Output {
@@ -204,7 +322,7 @@ impl Index<(usize, usize)> for Matrix {
     }
 }
 
-impl IndexMut<(usize, usize)> for Matrix {
+impl<T: Num + NumAssign + Copy> IndexMut<(usize, usize)> for Matrix<T> {
     /// Get mutable element by `(row, col)`.
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
         let (row, col) = index;
@@ -214,129 +332,236 @@ impl IndexMut<(usize, usize)> for Matrix {
 
 /* scalar math */
 
-impl Add<F> for Matrix {
+impl<T: Num + NumAssign + Copy> Add<T> for Matrix<T> {
     type Output = Self;
 
-    fn add(mut self, rhs: F) -> Self::Output {
+    fn add(mut self, rhs: T) -> Self::Output {
         self.array.iter_mut().for_each(|elem| *elem += rhs);
         self
     }
 }
 
-impl Sub<F> for Matrix {
+impl<T: Num + NumAssign + Copy> Sub<T> for Matrix<T> {
     type Output = Self;
 
-    fn sub(mut self, rhs: F) -> Self::Output {
+    fn sub(mut self, rhs: T) -> Self::Output {
         self.array.iter_mut().for_each(|elem| *elem -= rhs);
         self
     }
 }
 
-impl Mul<F> for Matrix {
+impl<T: Num + NumAssign + Copy> Mul<T> for Matrix<T> {
     type Output = Self;
 
-    fn mul(mut self, rhs: F) -> Self::Output {
+    fn mul(mut self, rhs: T) -> Self::Output {
         self.array.iter_mut().for_each(|elem| *elem *= rhs);
         self
     }
 }
 
-impl Div<F> for Matrix {
+impl<T: Num + NumAssign + Copy> Div<T> for Matrix<T> {
     type Output = Self;
 
-    fn div(mut self, rhs: F) -> Self::Output {
+    fn div(mut self, rhs: T) -> Self::Output {
         self.array.iter_mut().for_each(|elem| *elem /= rhs);
         self
     }
 }
 
-impl AddAssign<F> for Matrix {
-    fn add_assign(&mut self, rhs: F) {
+impl<T: Num + NumAssign + Copy> AddAssign<T> for Matrix<T> {
+    fn add_assign(&mut self, rhs: T) {
         self.array.iter_mut().for_each(|elem| *elem += rhs)
     }
 }
 
-impl SubAssign<F> for Matrix {
-    fn sub_assign(&mut self, rhs: F) {
+impl<T: Num + NumAssign + Copy> SubAssign<T> for Matrix<T> {
+    fn sub_assign(&mut self, rhs: T) {
         self.array.iter_mut().for_each(|elem| *elem -= rhs)
     }
 }
 
-impl MulAssign<F> for Matrix {
-    fn mul_assign(&mut self, rhs: F) {
+impl<T: Num + NumAssign + Copy> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.array.iter_mut().for_each(|elem| *elem *= rhs)
     }
 }
 
-impl DivAssign<F> for Matrix {
-    fn div_assign(&mut self, rhs: F) {
+impl<T: Num + NumAssign + Copy> DivAssign<T> for Matrix<T> {
+    fn div_assign(&mut self, rhs: T) {
         self.array.iter_mut().for_each(|elem| *elem /= rhs)
     }
 }
 
 /* matrix operations */
 
-impl Add for Matrix {
+/// An error describing a shape mismatch between two matrices in an operation that requires them
+/// to line up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeError {
+    /// An element-wise operation was attempted between matrices of different shapes.
+    Mismatched {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+    /// A dot product was attempted where `lhs`'s columns don't match `rhs`'s rows.
+    InnerMismatch {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+}
+
+impl std::fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShapeError::Mismatched { lhs, rhs } => write!(
+                f,
+                "matrix shapes do not match: {lhs:?} (rows, cols) vs {rhs:?}"
+            ),
+            ShapeError::InnerMismatch { lhs, rhs } => write!(
+                f,
+                "inner dimensions do not match for dot product: {lhs:?} (rows, cols) dot {rhs:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+impl<T: Num + NumAssign + Copy> Add for Matrix<T> {
     type Output = Self;
 
     fn add(mut self, rhs: Self) -> Self::Output {
-        // FIXME: Check sizes.
+        assert_eq!(
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols),
+            "cannot add matrices of shape {:?} and {:?}",
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols)
+        );
         self.array
             .iter_mut()
             .zip(rhs.array())
-            .for_each(|(a, b)| *a += b);
+            .for_each(|(a, b)| *a += *b);
 
         return self;
     }
 }
 
-impl Sub for Matrix {
+impl<T: Num + NumAssign + Copy> Sub for Matrix<T> {
     type Output = Self;
 
     fn sub(mut self, rhs: Self) -> Self::Output {
-        // FIXME: Check sizes.
+        assert_eq!(
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols),
+            "cannot subtract matrices of shape {:?} and {:?}",
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols)
+        );
         self.array
             .iter_mut()
             .zip(rhs.array())
-            .for_each(|(a, b)| *a -= b);
+            .for_each(|(a, b)| *a -= *b);
 
         return self;
     }
 }
 
-impl Div for Matrix {
+impl<T: Num + NumAssign + Copy> Div for Matrix<T> {
     type Output = Self;
 
     fn div(mut self, rhs: Self) -> Self::Output {
-        // FIXME: Check sizes.
+        assert_eq!(
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols),
+            "cannot divide matrices of shape {:?} and {:?}",
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols)
+        );
         self.array
             .iter_mut()
             .zip(rhs.array())
-            .for_each(|(a, b)| *a /= b);
+            .for_each(|(a, b)| *a /= *b);
 
         return self;
     }
 }
 
 // TODO: Is there a way to make this less error-prone?
-impl Mul for Matrix {
+impl<T: Num + NumAssign + Copy> Mul for Matrix<T> {
     type Output = Self;
 
     /// # Note
     ///
     /// This is a element by element multiplication, not a dot product or cross product.
     fn mul(mut self, rhs: Self) -> Self::Output {
-        // FIXME: Check sizes.
+        assert_eq!(
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols),
+            "cannot multiply matrices of shape {:?} and {:?} element-wise",
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols)
+        );
         self.array
             .iter_mut()
             .zip(rhs.array())
-            .for_each(|(a, b)| *a *= b);
+            .for_each(|(a, b)| *a *= *b);
 
         return self;
     }
 }
 
-impl Matrix {
+impl<T: Num + NumAssign + Copy> Matrix<T> {
+    /// Element-wise addition that reports a shape mismatch instead of panicking.
+    pub fn try_add(self, rhs: Self) -> Result<Self, ShapeError> {
+        if (self.rows, self.cols) != (rhs.rows, rhs.cols) {
+            return Err(ShapeError::Mismatched {
+                lhs: (self.rows, self.cols),
+                rhs: (rhs.rows, rhs.cols),
+            });
+        }
+
+        Ok(self + rhs)
+    }
+
+    /// Element-wise subtraction that reports a shape mismatch instead of panicking.
+    pub fn try_sub(self, rhs: Self) -> Result<Self, ShapeError> {
+        if (self.rows, self.cols) != (rhs.rows, rhs.cols) {
+            return Err(ShapeError::Mismatched {
+                lhs: (self.rows, self.cols),
+                rhs: (rhs.rows, rhs.cols),
+            });
+        }
+
+        Ok(self - rhs)
+    }
+
+    /// Element-wise multiplication that reports a shape mismatch instead of panicking.
+    pub fn try_mul_elementwise(self, rhs: Self) -> Result<Self, ShapeError> {
+        if (self.rows, self.cols) != (rhs.rows, rhs.cols) {
+            return Err(ShapeError::Mismatched {
+                lhs: (self.rows, self.cols),
+                rhs: (rhs.rows, rhs.cols),
+            });
+        }
+
+        Ok(self * rhs)
+    }
+
+    /// The dot product, reporting mismatched inner dimensions instead of panicking.
+    pub fn try_dot(self, rhs: Self) -> Result<Self, ShapeError> {
+        if self.cols != rhs.rows {
+            return Err(ShapeError::InnerMismatch {
+                lhs: (self.rows, self.cols),
+                rhs: (rhs.rows, rhs.cols),
+            });
+        }
+
+        Ok(self.dot(rhs))
+    }
+}
+
+impl<T: Num + NumAssign + Copy> Matrix<T> {
     /// The dot product between two matrices.
     ///
     /// From m × n matrix A and n × p matrix B, we can calculate the dot product AB = C where C
@@ -372,13 +597,18 @@ impl Matrix {
     ///
     /// ```should_panic
     /// # use matrijs::Matrix;
-    /// let e = Matrix::one(3, 4);
+    /// let e = Matrix::<f32>::one(3, 4);
     /// let f = Matrix::one(3, 2);
     ///
     /// e.dot(f);
     /// ```
     pub fn dot(self, rhs: Self) -> Self {
-        assert_eq!(self.cols, rhs.rows);
+        assert_eq!(
+            self.cols, rhs.rows,
+            "inner dimensions do not match for dot product: {:?} (rows, cols) dot {:?}",
+            (self.rows, self.cols),
+            (rhs.rows, rhs.cols)
+        );
 
         let m = self.rows;
         let n = self.cols;
@@ -401,6 +631,314 @@ impl Matrix {
 
         c
     }
+
+    /// Multiply this matrix by a column vector: `A·v`.
+    ///
+    /// # Panics
+    ///
+    /// If `v.len()` does not equal `self.cols()`.
+    ///
+    /// ```
+    /// use matrijs::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+    /// assert_eq!(a.dot_vec(&[1.0, 1.0]), vec![1.0, 5.0]);
+    /// ```
+    pub fn dot_vec(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(
+            v.len(),
+            self.cols,
+            "v must have the same length as the number of columns ({} != {})",
+            v.len(),
+            self.cols
+        );
+
+        let mut result = vec![T::zero(); self.rows];
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                result[i] += self[(i, k)] * v[k];
+            }
+        }
+
+        result
+    }
+
+    /// Multiply a row vector by this matrix: `vᵀ·A`.
+    ///
+    /// # Panics
+    ///
+    /// If `v.len()` does not equal `self.rows()`.
+    ///
+    /// ```
+    /// use matrijs::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+    /// assert_eq!(a.vec_dot(&[1.0, 1.0]), vec![2.0, 4.0]);
+    /// ```
+    pub fn vec_dot(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(
+            v.len(),
+            self.rows,
+            "v must have the same length as the number of rows ({} != {})",
+            v.len(),
+            self.rows
+        );
+
+        let mut result = vec![T::zero(); self.cols];
+        for j in 0..self.cols {
+            for k in 0..self.rows {
+                result[j] += v[k] * self[(k, j)];
+            }
+        }
+
+        result
+    }
+}
+
+/* minors, cofactors and cofactor-expansion determinant */
+
+impl<T: Num + NumAssign + Copy> Matrix<T> {
+    /// Return the submatrix obtained by deleting `row` and `col`.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square, or smaller than 2×2.
+    pub fn minor(&self, row: usize, col: usize) -> Matrix<T> {
+        assert_eq!(
+            self.rows, self.cols,
+            "minor is only defined for square matrices"
+        );
+        assert!(self.rows >= 2, "minor requires at least a 2x2 matrix");
+
+        let size = self.rows - 1;
+        let mut array = Vec::with_capacity(size * size);
+        for i in 0..self.rows {
+            if i == row {
+                continue;
+            }
+            for j in 0..self.cols {
+                if j == col {
+                    continue;
+                }
+                array.push(self[(i, j)]);
+            }
+        }
+
+        Matrix::new(size, size, &array)
+    }
+
+    /// The `(i, j)` cofactor: `(-1)^(i+j) × minor(i, j).determinant()`.
+    pub fn cofactor(&self, i: usize, j: usize) -> T {
+        let sign = if (i + j).is_multiple_of(2) {
+            T::one()
+        } else {
+            T::zero() - T::one()
+        };
+
+        sign * self.minor(i, j).determinant()
+    }
+
+    /// The determinant of this (square) matrix.
+    ///
+    /// Uses direct formulas for 1×1 and 2×2 matrices, and Laplace cofactor expansion along the
+    /// first row for larger ones. This is exact (no divisions) and works for any signed `T`,
+    /// integers included, unlike the LU-based [`LUDecomposition::determinant`], which needs
+    /// [`Real`] and is faster for larger floating-point matrices. For an unsigned `T`, a matrix
+    /// larger than 2×2 overflows on the negative cofactor sign.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use matrijs::Matrix;
+    ///
+    /// let m = Matrix::new(2, 2, &[4, 3, 6, 3]);
+    /// assert_eq!(m.determinant(), -6);
+    /// ```
+    pub fn determinant(&self) -> T {
+        assert_eq!(
+            self.rows, self.cols,
+            "determinant is only defined for square matrices"
+        );
+
+        match self.rows {
+            1 => self[(0, 0)],
+            2 => self[(0, 0)] * self[(1, 1)] - self[(0, 1)] * self[(1, 0)],
+            _ => (0..self.cols).fold(T::zero(), |acc, j| acc + self[(0, j)] * self.cofactor(0, j)),
+        }
+    }
+}
+
+/* LU decomposition */
+
+/// The result of decomposing a [`Matrix`] into lower- and upper-triangular factors, with partial
+/// pivoting: `PA = LU`.
+///
+/// `L` (unit diagonal) and `U` are stored combined in a single matrix: `L`'s strictly-lower
+/// triangle lives below the diagonal, and `U` (including the diagonal) lives on and above it.
+/// `permutation[i]` is the row of the original matrix that ended up in row `i` after pivoting, and
+/// `parity` is `1.0` or `-1.0` depending on whether an even or odd number of row swaps were made.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LUDecomposition<T> {
+    lu: Matrix<T>,
+    permutation: Vec<usize>,
+    parity: T,
+}
+
+impl<T: Real> Matrix<T> {
+    /// Decompose this matrix into `L` and `U` factors using Doolittle elimination with partial
+    /// pivoting.
+    ///
+    /// Returns `None` if the matrix is singular (or too close to singular for pivoting to be
+    /// numerically sound).
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use matrijs::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, &[4.0, 3.0, 6.0, 3.0]);
+    /// let lu = a.lu().unwrap();
+    /// assert_eq!(lu.determinant(), -6.0);
+    /// ```
+    pub fn lu(&self) -> Option<LUDecomposition<T>> {
+        assert_eq!(self.rows, self.cols, "LU decomposition requires a square matrix");
+
+        let n = self.rows;
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut parity = T::one();
+
+        for k in 0..n {
+            // Find the largest-magnitude pivot in column k, at or below row k.
+            let mut pivot_row = k;
+            let mut pivot_value = lu[(k, k)].abs();
+            for i in (k + 1)..n {
+                let value = lu[(i, k)].abs();
+                if value > pivot_value {
+                    pivot_row = i;
+                    pivot_value = value;
+                }
+            }
+
+            if pivot_value < T::epsilon() {
+                return None;
+            }
+
+            if pivot_row != k {
+                for j in 0..n {
+                    lu.array.swap(k * n + j, pivot_row * n + j);
+                }
+                permutation.swap(k, pivot_row);
+                parity = -parity;
+            }
+
+            for i in (k + 1)..n {
+                let m = lu[(i, k)] / lu[(k, k)];
+                lu[(i, k)] = m;
+                for j in (k + 1)..n {
+                    let u_kj = lu[(k, j)];
+                    lu[(i, j)] -= m * u_kj;
+                }
+            }
+        }
+
+        Some(LUDecomposition {
+            lu,
+            permutation,
+            parity,
+        })
+    }
+
+    /// Solve `self x = b` for `x`.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square, singular, or `b.len()` does not match the matrix size.
+    pub fn solve(&self, b: &[T]) -> Vec<T> {
+        self.lu()
+            .expect("matrix is singular; cannot solve")
+            .solve(b)
+    }
+
+    /// The inverse of this (square) matrix, computed via LU decomposition.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square or is singular.
+    pub fn inverse(&self) -> Matrix<T> {
+        self.lu()
+            .expect("matrix is singular; cannot invert")
+            .inverse()
+    }
+}
+
+impl<T: Real> LUDecomposition<T> {
+    /// The determinant of the original matrix: `parity × ∏ U_ii`.
+    pub fn determinant(&self) -> T {
+        let n = self.lu.rows;
+        let diagonal_product = (0..n).fold(T::one(), |acc, i| acc * self.lu[(i, i)]);
+
+        self.parity * diagonal_product
+    }
+
+    /// Solve `A x = b` for `x`, where `A` is the matrix this decomposition was computed from.
+    ///
+    /// # Panics
+    ///
+    /// If `b.len()` does not match the size of the decomposed matrix.
+    pub fn solve(&self, b: &[T]) -> Vec<T> {
+        let n = self.lu.rows;
+        assert_eq!(b.len(), n, "b must have the same length as the matrix size");
+
+        // Apply the permutation to b, then forward-substitute with L (unit diagonal).
+        let mut x: Vec<T> = self.permutation.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                let l_ij = self.lu[(i, j)];
+                let x_j = x[j];
+                x[i] -= l_ij * x_j;
+            }
+        }
+
+        // Back-substitute with U.
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let u_ij = self.lu[(i, j)];
+                let x_j = x[j];
+                x[i] -= u_ij * x_j;
+            }
+            x[i] /= self.lu[(i, i)];
+        }
+
+        x
+    }
+
+    /// The inverse of the original matrix, found by solving against every column of the identity
+    /// matrix.
+    pub fn inverse(&self) -> Matrix<T> {
+        let n = self.lu.rows;
+        let mut inverse = Matrix::zero(n, n);
+
+        for col in 0..n {
+            let mut e = vec![T::zero(); n];
+            e[col] = T::one();
+
+            let x = self.solve(&e);
+            for (row, value) in x.into_iter().enumerate() {
+                inverse[(row, col)] = value;
+            }
+        }
+
+        inverse
+    }
 }
 
 #[cfg(test)]
@@ -410,10 +948,10 @@ mod tests {
     #[test]
     fn creation() {
         let _m = Matrix::new(2, 3, &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
-        let _o = Matrix::zero(2, 3);
-        let _l = Matrix::one(2, 3);
+        let _o = Matrix::<f32>::zero(2, 3);
+        let _l = Matrix::<f32>::one(2, 3);
         let _v = Matrix::with_value(2, 3, std::f32::consts::PI);
-        let _i = Matrix::identity(2);
+        let _i = Matrix::<f32>::identity(2);
         let _d = Matrix::diagonal(&[0.0, 1.0, 2.0, 3.0]);
     }
 
@@ -553,4 +1091,234 @@ mod tests {
         let manual_m = Matrix::new(3, 3, arr);
         assert_eq!(m, manual_m)
     }
+
+    #[test]
+    fn lu_determinant() {
+        let m = Matrix::new(2, 2, &[4.0, 3.0, 6.0, 3.0]);
+        let lu = m.lu().unwrap();
+
+        assert_eq!(lu.determinant(), -6.0);
+        assert_eq!(m.determinant(), -6.0);
+    }
+
+    #[test]
+    fn lu_singular() {
+        let m = Matrix::new(2, 2, &[1.0, 2.0, 2.0, 4.0]);
+        assert!(m.lu().is_none());
+        assert_eq!(m.determinant(), 0.0);
+    }
+
+    #[test]
+    fn lu_solve() {
+        #[rustfmt::skip]
+        let a = Matrix::new(3, 3, &[
+            2.0, 1.0, 1.0,
+            4.0, 3.0, 3.0,
+            8.0, 7.0, 9.0,
+        ]);
+        let b = [5.0, 11.0, 22.0];
+
+        let x = a.solve(&b);
+
+        // A·x should reproduce b.
+        let ax = a.clone().dot(Matrix::new(3, 1, &x));
+        assert_eq!(ax.col(0), b.to_vec());
+    }
+
+    #[test]
+    fn lu_inverse() {
+        let a = Matrix::new(2, 2, &[4.0, 7.0, 2.0, 6.0]);
+        let inverse = a.inverse();
+
+        #[rustfmt::skip]
+        let expected = Matrix::new(2, 2, &[
+            0.6, -0.7,
+            -0.2, 0.4,
+        ]);
+        for (got, want) in inverse.array().iter().zip(expected.array()) {
+            assert!((got - want).abs() < 1e-6, "{got} != {want}");
+        }
+
+        // A · A⁻¹ should be the identity.
+        let product = a.dot(inverse);
+        for (got, want) in product.array().iter().zip(Matrix::<f32>::identity(2).array()) {
+            assert!((got - want).abs() < 1e-6, "{got} != {want}");
+        }
+    }
+
+    #[test]
+    fn map() {
+        let m = Matrix::new(1, 3, &[-1.0, 0.0, 1.0]);
+        let relu = m.map(|x: f32| x.max(0.0));
+
+        assert_eq!(relu, Matrix::new(1, 3, &[0.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn map_mut() {
+        let mut m = Matrix::new(1, 3, &[-1.0, 0.0, 1.0]);
+        m.map_mut(|x| x * 2.0);
+
+        assert_eq!(m, Matrix::new(1, 3, &[-2.0, 0.0, 2.0]));
+    }
+
+    #[test]
+    fn dot_vec() {
+        let a = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(a.dot_vec(&[1.0, 1.0]), vec![1.0, 5.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_vec_mismatched_shape() {
+        let a = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        a.dot_vec(&[1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn vec_dot() {
+        let a = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(a.vec_dot(&[1.0, 1.0]), vec![2.0, 4.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_mismatched_shape_panics() {
+        let a = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        let b = Matrix::new(3, 3, &[0.0; 9]);
+        let _ = a + b;
+    }
+
+    #[test]
+    fn try_add_mismatched_shape() {
+        let a = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        let b = Matrix::new(3, 3, &[0.0; 9]);
+
+        assert_eq!(
+            a.try_add(b),
+            Err(ShapeError::Mismatched {
+                lhs: (2, 2),
+                rhs: (3, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn try_add_ok() {
+        let a = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        let b = Matrix::one(2, 2);
+
+        assert_eq!(a.try_add(b), Ok(Matrix::new(2, 2, &[1.0, 2.0, 3.0, 4.0])));
+    }
+
+    #[test]
+    fn try_dot_mismatched_inner_dimension() {
+        let a = Matrix::<f32>::one(3, 4);
+        let b = Matrix::one(3, 2);
+
+        assert_eq!(
+            a.try_dot(b),
+            Err(ShapeError::InnerMismatch {
+                lhs: (3, 4),
+                rhs: (3, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let m = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        let collected: Vec<f32> = m.iter().copied().collect();
+
+        assert_eq!(collected, vec![0.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut m = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        m.iter_mut().for_each(|x| *x *= 2.0);
+
+        assert_eq!(m, Matrix::new(2, 2, &[0.0, 2.0, 4.0, 6.0]));
+    }
+
+    #[test]
+    fn iter_rows() {
+        let m = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        let rows: Vec<&[f32]> = m.iter_rows().collect();
+
+        assert_eq!(rows, vec![&[0.0, 1.0][..], &[2.0, 3.0][..]]);
+    }
+
+    #[test]
+    fn indices() {
+        let m = Matrix::<f32>::zero(2, 2);
+        let idx: Vec<_> = m.indices().collect();
+
+        assert_eq!(idx, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn iter_indexed() {
+        let m = Matrix::new(2, 2, &[0.0, 1.0, 2.0, 3.0]);
+        let indexed: Vec<_> = m.iter_indexed().collect();
+
+        assert_eq!(
+            indexed,
+            vec![
+                ((0, 0), &0.0),
+                ((0, 1), &1.0),
+                ((1, 0), &2.0),
+                ((1, 1), &3.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn minor() {
+        #[rustfmt::skip]
+        let m = Matrix::new(3, 3, &[
+            1, 2, 3,
+            4, 5, 6,
+            7, 8, 9,
+        ]);
+
+        assert_eq!(m.minor(1, 1), Matrix::new(2, 2, &[1, 3, 7, 9]));
+    }
+
+    #[test]
+    fn cofactor() {
+        #[rustfmt::skip]
+        let m = Matrix::new(3, 3, &[
+            1, 2, 3,
+            4, 5, 6,
+            7, 8, 9,
+        ]);
+
+        assert_eq!(m.cofactor(0, 0), 5 * 9 - 6 * 8);
+        assert_eq!(m.cofactor(0, 1), -(4 * 9 - 6 * 7));
+    }
+
+    #[test]
+    fn determinant_small() {
+        assert_eq!(Matrix::new(1, 1, &[5]).determinant(), 5);
+        assert_eq!(Matrix::new(2, 2, &[4, 3, 6, 3]).determinant(), -6);
+    }
+
+    #[test]
+    fn determinant_expansion() {
+        #[rustfmt::skip]
+        let m = Matrix::new(3, 3, &[
+            6, 1, 1,
+            4, -2, 5,
+            2, 8, 7,
+        ]);
+
+        assert_eq!(m.determinant(), -306);
+    }
+
+    #[test]
+    fn matrix_macro() {
+        let m = matrix![0.0, 1.0; -1.0, 0.0];
+        assert_eq!(m, Matrix::new(2, 2, &[0.0, 1.0, -1.0, 0.0]));
+    }
 }